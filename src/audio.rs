@@ -0,0 +1,138 @@
+use bracket_lib::prelude::*;
+
+// Embed the sound clips the same way the tile font is embedded, so they
+// ship inside the WASM bundle instead of being loaded from disk at runtime.
+embedded_resource!(FLAP_SOUND, "../resources/flap.ogg");
+embedded_resource!(SCORE_SOUND, "../resources/score.ogg");
+embedded_resource!(CRASH_SOUND, "../resources/crash.ogg");
+
+// Links the embedded clips into bracket_lib's resource VFS. Call once at
+// startup, alongside the tile font's `link_resource!`.
+pub fn link_sounds() {
+    link_resource!(FLAP_SOUND, "resources/flap.ogg");
+    link_resource!(SCORE_SOUND, "resources/score.ogg");
+    link_resource!(CRASH_SOUND, "resources/crash.ogg");
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Sound {
+    Flap,
+    Score,
+    Crash,
+}
+
+impl Sound {
+    fn resource_path(self) -> &'static str {
+        match self {
+            Sound::Flap => "resources/flap.ogg",
+            Sound::Score => "resources/score.ogg",
+            Sound::Crash => "resources/crash.ogg",
+        }
+    }
+}
+
+// Thin cross-target wrapper so scenes can just call `sound.play(Sound::Flap)`
+// without caring whether playback goes through rodio or the Web Audio API,
+// matching the crate's dual-target (native/wasm32) build.
+pub struct SoundPlayer {
+    backend: backend::Backend,
+}
+
+impl SoundPlayer {
+    pub fn new() -> Self {
+        SoundPlayer {
+            backend: backend::Backend::new(),
+        }
+    }
+
+    pub fn play(&self, sound: Sound) {
+        self.backend.play(sound.resource_path());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    use std::io::Cursor;
+
+    use bracket_lib::prelude::embedding::EMBED;
+    use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+
+    pub struct Backend {
+        // `None` on a headless/no-audio machine; `play` is then a no-op
+        // rather than a startup panic. The `OutputStream` is kept alive for
+        // as long as the player exists - dropping it would tear down the device.
+        stream: Option<(OutputStream, OutputStreamHandle)>,
+    }
+
+    impl Backend {
+        pub fn new() -> Self {
+            Backend {
+                stream: OutputStream::try_default().ok(),
+            }
+        }
+
+        pub fn play(&self, resource_path: &str) {
+            let Some((_stream, handle)) = self.stream.as_ref() else {
+                return;
+            };
+            let Some(bytes) = EMBED.lock().get_resource(resource_path.to_string()) else {
+                return;
+            };
+            let Ok(sink) = Sink::try_new(handle) else {
+                return;
+            };
+            if let Ok(source) = Decoder::new(Cursor::new(bytes)) {
+                sink.append(source);
+                sink.detach();
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    use bracket_lib::prelude::embedding::EMBED;
+    use wasm_bindgen::JsCast;
+    use web_sys::AudioContext;
+
+    pub struct Backend {
+        context: Option<AudioContext>,
+    }
+
+    impl Backend {
+        pub fn new() -> Self {
+            Backend {
+                context: AudioContext::new().ok(),
+            }
+        }
+
+        pub fn play(&self, resource_path: &str) {
+            let (Some(context), Some(bytes)) = (
+                self.context.as_ref(),
+                EMBED.lock().get_resource(resource_path.to_string()),
+            ) else {
+                return;
+            };
+            let array = js_sys::Uint8Array::from(bytes.as_slice());
+            let Ok(decode_promise) = context.decode_audio_data(&array.buffer()) else {
+                return;
+            };
+            let context = context.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let Ok(audio_buffer) =
+                    wasm_bindgen_futures::JsFuture::from(decode_promise).await
+                else {
+                    return;
+                };
+                let Ok(source) = context.create_buffer_source() else {
+                    return;
+                };
+                source.set_buffer(Some(&audio_buffer.unchecked_into()));
+                if let Ok(destination) = context.destination() {
+                    let _ = source.connect_with_audio_node(&destination);
+                }
+                let _ = source.start();
+            });
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use bracket_lib::prelude::*;
+
+use crate::high_scores::HighScores;
+use crate::scene::{Scene, SceneTransition, SharedState};
+
+const MAX_INITIALS: usize = 3;
+
+// The game-over screen. The final score lives on `SharedState`, so this
+// scene only needs to remember the leaderboard and, if the run earned a
+// spot on it, the initials the player is typing in.
+pub struct EndScene {
+    high_scores: HighScores,
+    phase: Phase,
+}
+
+enum Phase {
+    EnterInitials(String),
+    ShowScores,
+}
+
+impl EndScene {
+    pub fn new(shared: &SharedState) -> Self {
+        let high_scores = HighScores::load();
+        let phase = if high_scores.is_high_score(shared.score) {
+            Phase::EnterInitials(String::new())
+        } else {
+            Phase::ShowScores
+        };
+        EndScene { high_scores, phase }
+    }
+}
+
+impl Scene for EndScene {
+    fn tick(&mut self, ctx: &mut BTerm, shared: &mut SharedState) -> Option<SceneTransition> {
+        ctx.cls();
+        ctx.print_centered(2, "You Died!");
+        ctx.print_centered(3, &format!("Score: {}", shared.score));
+        match &mut self.phase {
+            Phase::EnterInitials(initials) => {
+                ctx.print_centered(5, "New high score! Enter your initials:");
+                ctx.print_centered(6, initials);
+                if let Some(key) = ctx.key {
+                    match key {
+                        VirtualKeyCode::Return if !initials.is_empty() => {
+                            let initials = initials.clone();
+                            self.high_scores.insert(initials, shared.score);
+                            self.high_scores.save();
+                            self.phase = Phase::ShowScores;
+                        }
+                        VirtualKeyCode::Back => {
+                            initials.pop();
+                        }
+                        _ => {
+                            if initials.len() < MAX_INITIALS {
+                                if let Some(letter) = key_to_letter(key) {
+                                    initials.push(letter);
+                                }
+                            }
+                        }
+                    }
+                }
+                None
+            }
+            Phase::ShowScores => {
+                ctx.print_centered(5, "High Scores");
+                for (i, (initials, score)) in self.high_scores.entries().iter().enumerate() {
+                    ctx.print_centered(
+                        7 + i as i32,
+                        &format!("{}. {} - {}", i + 1, initials, score),
+                    );
+                }
+                ctx.print_centered(19, "Press (P) to restart");
+                ctx.print_centered(21, "Press (Q) to quit");
+                if let Some(key) = ctx.key {
+                    match key {
+                        VirtualKeyCode::P => return Some(SceneTransition::Play),
+                        VirtualKeyCode::Q => ctx.quitting = true,
+                        _ => {}
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+// Maps the A-Z virtual keys to uppercase ASCII letters for initials entry.
+fn key_to_letter(key: VirtualKeyCode) -> Option<char> {
+    let index = (key as u32).wrapping_sub(VirtualKeyCode::A as u32);
+    if index < 26 {
+        Some((b'A' + index as u8) as char)
+    } else {
+        None
+    }
+}
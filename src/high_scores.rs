@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+const MAX_ENTRIES: usize = 10;
+const STORAGE_KEY: &str = "flappy-dragon-high-scores";
+
+// A ranked, bounded leaderboard of initials and scores. Kept sorted
+// descending so `entries()` is always display-ready.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HighScores {
+    entries: Vec<(String, i32)>,
+}
+
+impl HighScores {
+    // Loads the leaderboard from disk (native) or `localStorage` (wasm32),
+    // falling back to an empty table if nothing has been saved yet.
+    pub fn load() -> Self {
+        storage::load().unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::save(self);
+    }
+
+    // True if `score` would earn a spot on the board, i.e. there's a free
+    // slot or it beats the current lowest entry.
+    pub fn is_high_score(&self, score: i32) -> bool {
+        self.entries.len() < MAX_ENTRIES
+            || self.entries.last().map_or(true, |(_, lowest)| score > *lowest)
+    }
+
+    pub fn insert(&mut self, initials: String, score: i32) {
+        self.entries.push((initials, score));
+        self.entries.sort_by(|a, b| b.1.cmp(&a.1));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    pub fn entries(&self) -> &[(String, i32)] {
+        &self.entries
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod storage {
+    use super::{HighScores, STORAGE_KEY};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn file_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("flappy-dragon").join(format!("{STORAGE_KEY}.json")))
+    }
+
+    pub fn load() -> Option<HighScores> {
+        let data = fs::read_to_string(file_path()?).ok()?;
+        serde_json::from_str(&data).ok()
+    }
+
+    pub fn save(scores: &HighScores) {
+        let Some(path) = file_path() else { return };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(data) = serde_json::to_string(scores) {
+            let _ = fs::write(path, data);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod storage {
+    use super::{HighScores, STORAGE_KEY};
+
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    pub fn load() -> Option<HighScores> {
+        let data = local_storage()?.get_item(STORAGE_KEY).ok()??;
+        serde_json::from_str(&data).ok()
+    }
+
+    pub fn save(scores: &HighScores) {
+        let Some(storage) = local_storage() else { return };
+        if let Ok(data) = serde_json::to_string(scores) {
+            let _ = storage.set_item(STORAGE_KEY, &data);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bracket_lib::prelude::*;
+
+use crate::high_scores::HighScores;
+use crate::scene::{Scene, SceneTransition, SharedState};
+
+// Read-only leaderboard view, reachable from the main menu without playing
+// a round first.
+pub struct HighScoresScene {
+    high_scores: HighScores,
+}
+
+impl HighScoresScene {
+    pub fn new() -> Self {
+        HighScoresScene {
+            high_scores: HighScores::load(),
+        }
+    }
+}
+
+impl Scene for HighScoresScene {
+    fn tick(&mut self, ctx: &mut BTerm, _shared: &mut SharedState) -> Option<SceneTransition> {
+        ctx.cls();
+        ctx.print_color_centered(2, GREEN, BLACK, "High Scores");
+        if self.high_scores.entries().is_empty() {
+            ctx.print_centered(5, "No scores yet - go fly!");
+        } else {
+            for (i, (initials, score)) in self.high_scores.entries().iter().enumerate() {
+                ctx.print_centered(4 + i as i32, &format!("{}. {} - {}", i + 1, initials, score));
+            }
+        }
+        ctx.print_centered(20, "Press (ESC) to return to the menu");
+        if let Some(VirtualKeyCode::Escape) = ctx.key {
+            return Some(SceneTransition::MainMenu);
+        }
+        None
+    }
+}
@@ -0,0 +1,31 @@
+use bracket_lib::prelude::*;
+
+use crate::scene::{Scene, SceneTransition, SharedState};
+
+// The main menu screen.
+pub struct MenuScene;
+
+impl MenuScene {
+    pub fn new() -> Self {
+        MenuScene
+    }
+}
+
+impl Scene for MenuScene {
+    fn tick(&mut self, ctx: &mut BTerm, _shared: &mut SharedState) -> Option<SceneTransition> {
+        ctx.cls();
+        ctx.print_color_centered(5, GREEN, BLACK, "Welcome to Flappy Dragon");
+        ctx.print_color_centered(7, VIOLET, BLACK, "Press (P) to start");
+        ctx.print_color_centered(9, CYAN, BLACK, "Press (V) to view high scores");
+        ctx.print_color_centered(11, RED, BLACK, "Press (Q) to quit");
+        if let Some(key) = ctx.key {
+            match key {
+                VirtualKeyCode::P => return Some(SceneTransition::Play),
+                VirtualKeyCode::V => return Some(SceneTransition::HighScores),
+                VirtualKeyCode::Q => ctx.quitting = true,
+                _ => {}
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::VecDeque;
+
+use bracket_lib::prelude::*;
+
+use crate::audio::Sound;
+use crate::scene::{Scene, SceneTransition, SharedState};
+use crate::{FRAME_DURATION, OBSTACLE_SPAWN_INTERVAL, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+// Dragon Frames
+const DRAGON_FRAMES: [u16; 6] = [64, 1, 2, 3, 2, 1];
+
+// The dragon is rendered as a 2x2 fancy sprite, so its hit box covers the
+// same footprint rather than the single point the old collision check used.
+const PLAYER_WIDTH: i32 = 2;
+const PLAYER_HEIGHT: i32 = 2;
+
+// How far (in screen rows) a single held flap can carry the player upward
+// before gravity takes back over, whether or not Space is still held.
+const MAX_JUMP_HEIGHT: f32 = 8.0;
+
+// Axis-aligned bounding box, used for collision between the player and
+// obstacle bars.
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+}
+
+impl Rect {
+    fn new(x0: i32, y0: i32, x1: i32, y1: i32) -> Self {
+        Rect { x0, y0, x1, y1 }
+    }
+    fn intersects(&self, other: &Rect) -> bool {
+        self.x0 < other.x1 && self.x1 > other.x0 && self.y0 < other.y1 && self.y1 > other.y0
+    }
+}
+
+// Create a player struct
+struct Player {
+    x: i32,
+    y: f32,
+    velocity: f32,
+    frame: usize, // Usize to index arrays
+    jumping: bool,
+    jump_start_y: f32,
+    // Cleared once a flap hits the height ceiling and only set again once
+    // Space is released, so a single held key press
+    // can't re-arm a fresh climb every tick and fly forever.
+    can_jump: bool,
+}
+
+// Constructor for the player struct
+impl Player {
+    fn new(x: i32, y: i32) -> Player {
+        Player {
+            x: x,
+            y: y as f32,
+            velocity: 0.0,
+            frame: 0,
+            jumping: false,
+            jump_start_y: 0.0,
+            can_jump: true,
+        }
+    }
+    // Applies gravity and horizontal movement every tick. While `space_held`
+    // is true and the player hasn't yet risen `MAX_JUMP_HEIGHT` since the
+    // flap began, upward velocity is held steady instead of decaying, giving
+    // a taller flap the longer Space is held. Releasing Space or hitting the
+    // height ceiling hands control straight back to gravity, and Space must
+    // be released before another flap can start. Returns true the tick a new
+    // flap begins, so callers can fire a one-shot sound cue off the edge.
+    fn gravity_and_move(&mut self, space_held: bool) -> bool {
+        if !space_held {
+            self.jumping = false;
+            self.can_jump = true;
+        } else if self.can_jump && !self.jumping {
+            self.jumping = true;
+            self.jump_start_y = self.y;
+        }
+        let flap_started = self.jumping && self.jump_start_y == self.y;
+
+        if self.jumping && self.jump_start_y - self.y < MAX_JUMP_HEIGHT {
+            self.velocity = -2.0;
+        } else {
+            if self.jumping {
+                // Hit the ceiling while Space is still held; require a
+                // release before the next flap can start.
+                self.can_jump = false;
+            }
+            self.jumping = false;
+            if self.velocity < 2.0 {
+                self.velocity += 0.2;
+            }
+        }
+        self.y += self.velocity;
+        self.x += 1;
+        if self.y < 0.0 {
+            // Carry the clipped distance into the jump's start so the rise
+            // still counts toward MAX_JUMP_HEIGHT at the top of the screen.
+            self.jump_start_y -= self.y;
+            self.y = 0.0;
+        }
+        flap_started
+    }
+    fn render(&mut self, ctx: &mut BTerm) {
+        ctx.set_active_console(1);
+        ctx.cls();
+        ctx.set_fancy(
+            PointF::new(0.0, self.y),
+            1,
+            Degrees::new(0.0),
+            PointF::new(2.0, 2.0),
+            WHITE,
+            NAVY,
+            DRAGON_FRAMES[self.frame],
+        );
+        ctx.set_active_console(0);
+    }
+    fn bounds(&self) -> Rect {
+        Rect::new(
+            self.x,
+            self.y as i32,
+            self.x + PLAYER_WIDTH,
+            self.y as i32 + PLAYER_HEIGHT,
+        )
+    }
+}
+
+// Create an obstacle struct
+struct Obstacle {
+    x: i32,
+    gap_y: i32,
+    size: i32,
+    scored: bool,
+}
+
+// Constructor for the obstacle struct
+impl Obstacle {
+    fn new(x: i32, score: i32, rng: &mut RandomNumberGenerator) -> Self {
+        Obstacle {
+            x,
+            gap_y: rng.range(10, 40),
+            size: i32::max(2, 20 - score),
+            scored: false,
+        }
+    }
+    fn render(&mut self, ctx: &mut BTerm, player_x: i32) {
+        let screen_x = self.x - player_x;
+        let half_size = self.size / 2;
+
+        // Draw the top half of the obstacle
+        for y in 0..self.gap_y - half_size {
+            ctx.set(screen_x, y, RED, BLACK, to_cp437('|'));
+        }
+
+        // Draw the bottom half of the obstacle
+        for y in self.gap_y + half_size..self.gap_y + self.size {
+            ctx.set(screen_x, y, RED, BLACK, to_cp437('|'));
+        }
+    }
+    fn hit_obstacle(&self, player: &Player) -> bool {
+        let half_size = self.size / 2;
+        let top_bar = Rect::new(self.x, 0, self.x + 1, self.gap_y - half_size);
+        let bottom_bar = Rect::new(self.x, self.gap_y + half_size, self.x + 1, SCREEN_HEIGHT);
+        let player_box = player.bounds();
+        player_box.intersects(&top_bar) || player_box.intersects(&bottom_bar)
+    }
+}
+
+// The screen shown while the player is actively flying. Owns the obstacle
+// queue and spawn timer, both of which only make sense while playing.
+pub struct PlayScene {
+    player: Player,
+    frame_time: f32,
+    obstacles: VecDeque<Obstacle>,
+    obstacle_spawn_time: f32,
+    // Toggled with F1; draws tuning info over the scene with no cost when off.
+    debug: bool,
+    f1_held: bool,
+}
+
+impl PlayScene {
+    pub fn new(shared: &mut SharedState) -> Self {
+        let mut obstacles = VecDeque::new();
+        obstacles.push_back(Obstacle::new(SCREEN_WIDTH + 10, 0, &mut shared.rng));
+        PlayScene {
+            player: Player::new(5, 25),
+            frame_time: 0.0,
+            obstacles,
+            obstacle_spawn_time: 0.0,
+            debug: false,
+            f1_held: false,
+        }
+    }
+
+    // FPS, player physics, obstacle queue depth and the next gap, plus the
+    // player's collision box outline - everything needed to tune gravity,
+    // frame duration and the difficulty curve without recompiling.
+    fn render_debug_overlay(&self, ctx: &mut BTerm) {
+        let fps = if ctx.frame_time_ms > 0.0 {
+            1000.0 / ctx.frame_time_ms
+        } else {
+            0.0
+        };
+        ctx.print(0, 3, &format!("FPS: {fps:.1}"));
+        ctx.print(
+            0,
+            4,
+            &format!("y: {:.2}  velocity: {:.2}", self.player.y, self.player.velocity),
+        );
+        ctx.print(0, 5, &format!("obstacles: {}", self.obstacles.len()));
+        if let Some(next) = self.obstacles.front() {
+            ctx.print(
+                0,
+                6,
+                &format!("next gap_y: {} size: {}", next.gap_y, next.size),
+            );
+        }
+
+        // The player is always drawn at screen x=0 (the world scrolls past
+        // it, per `Obstacle::render`'s `screen_x = self.x - player_x`), so
+        // shift the world-space collision box into screen space to match.
+        let bounds = self.player.bounds();
+        let screen_x0 = bounds.x0 - self.player.x;
+        let screen_x1 = bounds.x1 - self.player.x;
+        for x in screen_x0..screen_x1 {
+            ctx.set(x, bounds.y0, YELLOW, BLACK, to_cp437('-'));
+            ctx.set(x, bounds.y1 - 1, YELLOW, BLACK, to_cp437('-'));
+        }
+        for y in bounds.y0..bounds.y1 {
+            ctx.set(screen_x0, y, YELLOW, BLACK, to_cp437('|'));
+            ctx.set(screen_x1 - 1, y, YELLOW, BLACK, to_cp437('|'));
+        }
+    }
+}
+
+impl Scene for PlayScene {
+    fn tick(&mut self, ctx: &mut BTerm, shared: &mut SharedState) -> Option<SceneTransition> {
+        ctx.cls_bg(NAVY);
+        let f1_held = matches!(ctx.key, Some(VirtualKeyCode::F1));
+        if f1_held && !self.f1_held {
+            self.debug = !self.debug;
+        }
+        self.f1_held = f1_held;
+
+        let space_held = matches!(ctx.key, Some(VirtualKeyCode::Space));
+        self.frame_time += ctx.frame_time_ms;
+        if self.frame_time > FRAME_DURATION {
+            self.frame_time = 0.0;
+            if self.player.gravity_and_move(space_held) {
+                shared.sound.play(Sound::Flap);
+            }
+        }
+        self.player.render(ctx);
+        ctx.print(0, 0, "Hold space to flap");
+        ctx.print(0, 1, &format!("Score: {}", shared.score));
+
+        // Spawn a new obstacle once the accumulated time crosses the interval;
+        // difficulty scales by shortening the interval with the player's score.
+        self.obstacle_spawn_time += ctx.frame_time_ms;
+        let spawn_interval = f32::max(400.0, OBSTACLE_SPAWN_INTERVAL - shared.score as f32 * 20.0);
+        if self.obstacle_spawn_time > spawn_interval {
+            self.obstacle_spawn_time = 0.0;
+            self.obstacles.push_back(Obstacle::new(
+                self.player.x + SCREEN_WIDTH,
+                shared.score,
+                &mut shared.rng,
+            ));
+        }
+
+        for obstacle in &mut self.obstacles {
+            obstacle.render(ctx, self.player.x);
+        }
+
+        // Award a point the first time the player clears each obstacle, then
+        // drop obstacles once they have scrolled off the left edge.
+        for obstacle in &mut self.obstacles {
+            if !obstacle.scored && self.player.x > obstacle.x {
+                obstacle.scored = true;
+                shared.score += 1;
+                shared.sound.play(Sound::Score);
+            }
+        }
+        while let Some(obstacle) = self.obstacles.front() {
+            if obstacle.x < self.player.x {
+                self.obstacles.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let hit = self
+            .obstacles
+            .iter()
+            .any(|obstacle| obstacle.hit_obstacle(&self.player));
+        if self.player.y as i32 > SCREEN_HEIGHT || hit {
+            shared.sound.play(Sound::Crash);
+            return Some(SceneTransition::End);
+        }
+
+        if self.debug {
+            self.render_debug_overlay(ctx);
+        }
+        None
+    }
+}
@@ -0,0 +1,38 @@
+use bracket_lib::prelude::*;
+
+use crate::audio::SoundPlayer;
+
+// Data that outlives any single screen: the running score, the RNG used to
+// generate obstacles, and the sound player, so swapping scenes doesn't reset
+// the sequence of random numbers, the player's progress, or the audio device.
+pub struct SharedState {
+    pub score: i32,
+    pub rng: RandomNumberGenerator,
+    pub sound: SoundPlayer,
+}
+
+impl SharedState {
+    pub fn new() -> Self {
+        SharedState {
+            score: 0,
+            rng: RandomNumberGenerator::new(),
+            sound: SoundPlayer::new(),
+        }
+    }
+}
+
+// Where the game should go next once the current scene's `tick` returns.
+pub enum SceneTransition {
+    MainMenu,
+    Play,
+    End,
+    HighScores,
+}
+
+// A single screen of the game (menu, play, game-over, ...). `State` owns a
+// `Box<dyn Scene>` and swaps it out whenever `tick` returns a transition, so
+// adding a new screen is a matter of implementing this trait rather than
+// editing a central match.
+pub trait Scene {
+    fn tick(&mut self, ctx: &mut BTerm, shared: &mut SharedState) -> Option<SceneTransition>;
+}